@@ -13,7 +13,7 @@
 //! *rw.write().unwrap() = 1;              // and write.
 //!
 //! std::thread::spawn(move|| {
-//! 	assert!(*ro.read().unwrap() == 1); // This one can only read.
+//!     assert!(*ro.read().unwrap() == 1); // This one can only read.
 //! });
 //! ```
 //! - `thread_1` still has full read/write control
@@ -48,15 +48,102 @@
 //! let clone = ro.clone();
 //! ```
 
+use std::marker::PhantomData;
+use std::ops::Deref;
 use std::sync::*;
 
+//---------------------------------------------------------------------------------------------------- ReadLock
+/// The read-only interface [`RoLock`] requires from its inner lock.
+///
+/// This is what lets [`RoLock`] abstract over lock backends: the default is
+/// [`std::sync::RwLock`], but any type implementing `ReadLock<T>` — e.g.
+/// `parking_lot::RwLock` behind the `parking_lot` feature — works too.
+///
+/// Only the read half is exposed; [`RoLock`] never takes a write lock. Its only
+/// mutable access is [`RoLock::get_mut`], which works without locking when it
+/// is the sole owner.
+pub trait ReadLock<T> {
+	/// The value returned by [`ReadLock::read`].
+	///
+	/// For [`std::sync::RwLock`] this is a [`Result`] (poisoning), while a
+	/// non-poisoning lock may return the guard directly.
+	type Read<'a> where Self: 'a, T: 'a;
+	/// The value returned by [`ReadLock::try_read`].
+	type TryRead<'a> where Self: 'a, T: 'a;
+
+	/// Creates a new lock holding `value`.
+	fn new(value: T) -> Self;
+	/// Acquires a shared read lock.
+	fn read(&self) -> Self::Read<'_>;
+	/// Attempts to acquire a shared read lock without blocking.
+	fn try_read(&self) -> Self::TryRead<'_>;
+	/// Whether the lock is poisoned (always `false` for non-poisoning locks).
+	fn is_poisoned(&self) -> bool;
+}
+
+impl<T> ReadLock<T> for RwLock<T> {
+	type Read<'a> = Result<RwLockReadGuard<'a, T>, PoisonError<RwLockReadGuard<'a, T>>> where Self: 'a, T: 'a;
+	type TryRead<'a> = TryLockResult<RwLockReadGuard<'a, T>> where Self: 'a, T: 'a;
+
+	#[inline(always)]
+	fn new(value: T) -> Self {
+		RwLock::new(value)
+	}
+
+	#[inline(always)]
+	fn read(&self) -> Self::Read<'_> {
+		RwLock::read(self)
+	}
+
+	#[inline(always)]
+	fn try_read(&self) -> Self::TryRead<'_> {
+		RwLock::try_read(self)
+	}
+
+	#[inline(always)]
+	fn is_poisoned(&self) -> bool {
+		RwLock::is_poisoned(self)
+	}
+}
+
+#[cfg(feature = "parking_lot")]
+impl<T> ReadLock<T> for parking_lot::RwLock<T> {
+	type Read<'a> = parking_lot::RwLockReadGuard<'a, T> where Self: 'a, T: 'a;
+	type TryRead<'a> = Option<parking_lot::RwLockReadGuard<'a, T>> where Self: 'a, T: 'a;
+
+	#[inline(always)]
+	fn new(value: T) -> Self {
+		parking_lot::RwLock::new(value)
+	}
+
+	#[inline(always)]
+	fn read(&self) -> Self::Read<'_> {
+		parking_lot::RwLock::read(self)
+	}
+
+	#[inline(always)]
+	fn try_read(&self) -> Self::TryRead<'_> {
+		parking_lot::RwLock::try_read(self)
+	}
+
+	#[inline(always)]
+	fn is_poisoned(&self) -> bool {
+		// `parking_lot` locks never poison.
+		false
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- RoLock
 /// Read Only Lock.
 ///
 /// This is a wrapper around [`Arc<RwLock<T>>`] that only implements [`RwLock::read()`] operations.
+///
+/// The inner lock `L` defaults to [`std::sync::RwLock`] but may be any
+/// [`ReadLock`] backend (see that trait for details).
 #[derive(Debug)]
-pub struct RoLock<T>(Arc<RwLock<T>>);
+pub struct RoLock<T, L = RwLock<T>>(Arc<L>, PhantomData<T>);
 
-impl<T: std::fmt::Debug> RoLock<T> {
+impl<T, L: ReadLock<T>> RoLock<T, L> {
 	#[inline(always)]
 	/// Get an [`Arc`] to an existing [`Arc<RwLock<T>>`] but as a [`RoLock`].
 	/// ```rust
@@ -68,10 +155,84 @@ impl<T: std::fmt::Debug> RoLock<T> {
 	///
 	/// assert!(*rw.read().unwrap() == *ro.read().unwrap());
 	/// ```
-	pub fn new(value: &Arc<RwLock<T>>) -> Self {
+	pub fn new(value: &Arc<L>) -> Self {
 		Self::from(value)
 	}
 
+	#[inline(always)]
+	/// Calls [`ReadLock::read`] on the inner lock ([`RwLock::read`] by default).
+	pub fn read(&self) -> L::Read<'_> {
+		self.0.read()
+	}
+
+	#[inline(always)]
+	/// Calls [`ReadLock::try_read`] on the inner lock ([`RwLock::try_read`] by default).
+	pub fn try_read(&self) -> L::TryRead<'_> {
+		self.0.try_read()
+	}
+
+	#[inline(always)]
+	/// Calls [`ReadLock::is_poisoned`] on the inner lock ([`RwLock::is_poisoned`] by default).
+	pub fn is_poisoned(&self) -> bool {
+		self.0.is_poisoned()
+	}
+
+	#[inline(always)]
+	/// Gets the number of [`RoLock`]'s pointing to the same data.
+	///
+	/// [`RoLock::new_pair`] creates 2 [`Arc`]'s:
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// # use std::sync::Arc;
+	/// let (rw, ro) = RoLock::new_pair(0);
+	/// assert!(Arc::strong_count(&rw) == 2);
+	/// assert!(ro.strong_count()      == 2);
+	///
+	/// drop(rw);
+	/// assert!(ro.strong_count() == 1);
+	/// ```
+	///
+	/// Calls [`Arc::strong_count`].
+	pub fn strong_count(&self) -> usize {
+		Arc::strong_count(&self.0)
+	}
+
+	#[inline(always)]
+	/// Creates a new [`RoWeak`] pointer to this data.
+	///
+	/// The returned handle does not keep the guarded data alive; see [`RoWeak`].
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// let (_rw, ro) = RoLock::new_pair(0);
+	/// let weak = ro.downgrade();
+	///
+	/// assert!(weak.upgrade().is_some());
+	/// ```
+	///
+	/// Calls [`Arc::downgrade`].
+	pub fn downgrade(&self) -> RoWeak<T, L> {
+		RoWeak(Arc::downgrade(&self.0), PhantomData)
+	}
+
+	#[inline(always)]
+	/// Gets the number of [`RoWeak`] handles pointing to the same data.
+	///
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// let (_rw, ro) = RoLock::new_pair(0);
+	/// assert!(ro.weak_count() == 0);
+	///
+	/// let _weak = ro.downgrade();
+	/// assert!(ro.weak_count() == 1);
+	/// ```
+	///
+	/// Calls [`Arc::weak_count`].
+	pub fn weak_count(&self) -> usize {
+		Arc::weak_count(&self.0)
+	}
+}
+
+impl<T: std::fmt::Debug> RoLock<T, RwLock<T>> {
 	#[inline(always)]
 	/// Creates a whole new [`Arc<RwLock<T>>`], returning it and an associated [`RoLock`].
 	/// ```rust
@@ -82,7 +243,7 @@ impl<T: std::fmt::Debug> RoLock<T> {
 	/// ```
 	pub fn new_pair(value: T) -> (Arc<RwLock<T>>, Self) {
 		let rw = Arc::new(RwLock::new(value));
-		let ro = Self::from(&rw);
+		let ro = Self::new(&rw);
 		(rw, ro)
 	}
 
@@ -103,41 +264,75 @@ impl<T: std::fmt::Debug> RoLock<T> {
 	}
 
 	#[inline(always)]
-	/// Calls [`RwLock::read`].
-	pub fn read(&self) -> Result<RwLockReadGuard<'_, T>, PoisonError<RwLockReadGuard<'_, T>>> {
-		self.0.read()
-	}
-
-	#[inline(always)]
-	/// Calls [`RwLock::try_read`].
-	pub fn try_read(&self) -> TryLockResult<RwLockReadGuard<'_, T>> {
-		self.0.try_read()
+	/// Acquires a read lock and projects the guard down to part of the data.
+	///
+	/// This calls [`RwLock::read`] and, on success, applies `f` to produce a
+	/// [`RoLockReadGuard`] that [`Deref`](std::ops::Deref)'s to only the `U`
+	/// returned by the closure, while keeping the underlying [`RwLockReadGuard`]
+	/// (and therefore the read lock) alive:
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// # use std::net::Ipv4Addr;
+	/// #[derive(Debug)]
+	/// struct Config { ip: Ipv4Addr, name: String }
+	///
+	/// let (_rw, ro) = RoLock::new_pair(Config {
+	///     ip: Ipv4Addr::LOCALHOST,
+	///     name: "server".into(),
+	/// });
+	///
+	/// // Narrow the guard down to just the `ip` field.
+	/// let ip = ro.map(|c| &c.ip).unwrap();
+	/// assert!(*ip == Ipv4Addr::LOCALHOST);
+	/// ```
+	///
+	/// The closure must return a reference *into* the guarded value, the same
+	/// contract as [`MappedRwLockReadGuard`](std::sync::MappedRwLockReadGuard).
+	///
+	/// # Errors
+	/// Propagates the [`PoisonError`] from [`RwLock::read`] if a writer panicked.
+	pub fn map<U, F>(&self, f: F) -> Result<RoLockReadGuard<'_, T, U>, PoisonError<RwLockReadGuard<'_, T>>>
+	where
+		F: FnOnce(&T) -> &U,
+	{
+		let guard = self.0.read()?;
+		let ptr: *const U = f(&guard);
+		Ok(RoLockReadGuard { guard, ptr })
 	}
 
 	#[inline(always)]
-	/// Calls [`RwLock::is_poisoned`].
-	pub fn is_poisoned(&self) -> bool {
-		self.0.is_poisoned()
+	/// Like [`RoLock::read`], but transparently recovers from poisoning.
+	///
+	/// [`RwLock::read`] returns a [`PoisonError`] whenever a writer panicked,
+	/// even though the data may still be perfectly readable. `read_recover`
+	/// swallows that error via [`PoisonError::into_inner`] and hands back the
+	/// guard anyway — for consumers that knowingly tolerate partially-updated
+	/// state:
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// let (_rw, ro) = RoLock::new_pair(0);
+	/// assert!(*ro.read_recover() == 0);
+	/// ```
+	pub fn read_recover(&self) -> RwLockReadGuard<'_, T> {
+		match self.0.read() {
+			Ok(guard)   => guard,
+			Err(poison) => poison.into_inner(),
+		}
 	}
 
 	#[inline(always)]
-	/// Gets the number of [`RoLock`]'s pointing to the same data.
+	/// Calls [`RwLock::clear_poison`].
 	///
-	/// [`RoLock::new_pair`] creates 2 [`Arc`]'s:
+	/// Clearing the poison flag performs no write to the guarded value, so it
+	/// is compatible with the read-only contract of [`RoLock`]:
 	/// ```rust
 	/// # use rolock::RoLock;
-	/// # use std::sync::Arc;
-	/// let (rw, ro) = RoLock::new_pair(0);
-	/// assert!(Arc::strong_count(&rw) == 2);
-	/// assert!(ro.strong_count()      == 2);
-	///
-	/// drop(rw);
-	/// assert!(ro.strong_count() == 1);
+	/// let (_rw, ro) = RoLock::new_pair(0);
+	/// ro.clear_poison();
+	/// assert!(!ro.is_poisoned());
 	/// ```
-	///
-	/// Calls [`Arc::strong_count`].
-	pub fn strong_count(&self) -> usize {
-		Arc::strong_count(&self.0)
+	pub fn clear_poison(&self) {
+		self.0.clear_poison();
 	}
 
 	#[inline(always)]
@@ -170,12 +365,12 @@ impl<T: std::fmt::Debug> RoLock<T> {
 	pub fn into_inner(self) -> Result<T, IntoInnerError<T>> {
 		let rw = match Arc::try_unwrap(self.0) {
 			Ok(rw) => rw,
-			Err(e) => return Err(IntoInnerError::Multiple(RoLock(e))),
+			Err(e) => return Err(IntoInnerError::Multiple(RoLock(e, PhantomData))),
 		};
 
 		match RwLock::into_inner(rw) {
 			Ok(inner) => Ok(inner),
-			Err(_)    => return Err(IntoInnerError::Poison),
+			Err(_)    => Err(IntoInnerError::Poison),
 		}
 	}
 
@@ -206,6 +401,108 @@ impl<T: std::fmt::Debug> RoLock<T> {
 	pub fn into_inner_unchecked(self) -> T {
 		Arc::try_unwrap(self.0).unwrap().into_inner().unwrap()
 	}
+
+	#[inline(always)]
+	/// Returns a mutable reference to the data if this is the sole owner.
+	///
+	/// When this [`RoLock`] is provably the only remaining handle
+	/// (`strong_count() == 1` and `weak_count() == 0`) there is no aliasing risk, so the data may be
+	/// mutated without locking — the same reasoning as [`RwLock::get_mut`].
+	/// This enables the "finalize" pattern: collect data through read-only
+	/// handles during the concurrent phase, then mutate it in place once
+	/// single-ownership is reached, without the full [`RoLock::into_inner`]
+	/// consume.
+	///
+	/// Returns [`None`] if any other [`RoLock`], [`Arc`] or [`RoWeak`] still points
+	/// to the data, or if the inner [`RwLock`] is poisoned:
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// let (rw, mut ro) = RoLock::new_pair(0);
+	/// assert!(ro.get_mut().is_none()); // `rw` still shares the data.
+	/// ```
+	///
+	/// Once sole ownership is reached, the mutation succeeds:
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// let (_, mut ro) = RoLock::new_pair(0);
+	/// *ro.get_mut().unwrap() = 1;
+	/// assert!(*ro.read().unwrap() == 1);
+	/// ```
+	///
+	/// Calls [`Arc::get_mut`] and [`RwLock::get_mut`].
+	pub fn get_mut(&mut self) -> Option<&mut T> {
+		let rw = Arc::get_mut(&mut self.0)?;
+		RwLock::get_mut(rw).ok()
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- RoLockReadGuard
+/// A read guard projected down to part of the data it protects.
+///
+/// Returned by [`RoLock::map`]. It holds the original [`RwLockReadGuard`]
+/// — keeping the read lock held — while [`Deref`](std::ops::Deref)'ing to a
+/// `U` projected out of the guarded `T` by the user's closure.
+#[derive(Debug)]
+pub struct RoLockReadGuard<'a, T, U> {
+	// Held only to keep the read lock alive (RAII); the projected `U` is
+	// reached through `ptr`, so this field is never read directly.
+	#[allow(dead_code)]
+	guard: RwLockReadGuard<'a, T>,
+	ptr: *const U,
+}
+
+impl<T, U> Deref for RoLockReadGuard<'_, T, U> {
+	type Target = U;
+
+	#[inline(always)]
+	fn deref(&self) -> &U {
+		// SAFETY: `ptr` was produced by the user's closure from a reference
+		// *into* the guarded value, which lives behind the `Arc` and is kept
+		// alive by `self.guard`. It therefore stays valid for as long as
+		// `self` (and thus the read lock) is alive.
+		unsafe { &*self.ptr }
+	}
+}
+
+//---------------------------------------------------------------------------------------------------- RoWeak
+/// A weak, read-only handle to a [`RoLock`]'s data.
+///
+/// This is to [`RoLock`] what [`Weak`] is to [`Arc`]: it holds no strong
+/// reference, so it does not keep the guarded data alive, and
+/// [`RoWeak::upgrade`] only succeeds while some [`RoLock`] (or the original
+/// [`Arc`]) still exists. Useful for observer/subscriber registries that
+/// should not outlive the writer's data.
+#[derive(Debug)]
+pub struct RoWeak<T, L = RwLock<T>>(Weak<L>, PhantomData<T>);
+
+impl<T, L: ReadLock<T>> RoWeak<T, L> {
+	#[inline(always)]
+	/// Attempts to upgrade to a [`RoLock`], returning [`None`] if the data has already been dropped.
+	/// ```rust
+	/// # use rolock::RoLock;
+	/// let (rw, ro) = RoLock::new_pair(0);
+	/// let weak = ro.downgrade();
+	///
+	/// // The data is still alive.
+	/// assert!(weak.upgrade().is_some());
+	///
+	/// // Once every strong handle is gone, the weak handle dangles.
+	/// drop(rw);
+	/// drop(ro);
+	/// assert!(weak.upgrade().is_none());
+	/// ```
+	///
+	/// Calls [`Weak::upgrade`].
+	pub fn upgrade(&self) -> Option<RoLock<T, L>> {
+		Some(RoLock(self.0.upgrade()?, PhantomData))
+	}
+}
+
+impl<T, L> Clone for RoWeak<T, L> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		Self(Weak::clone(&self.0), PhantomData)
+	}
 }
 
 //---------------------------------------------------------------------------------------------------- Error
@@ -220,16 +517,16 @@ pub enum IntoInnerError<T> {
 }
 
 //---------------------------------------------------------------------------------------------------- Common Impls
-impl<T> Clone for RoLock<T> {
+impl<T, L> Clone for RoLock<T, L> {
 	#[inline(always)]
 	fn clone(&self) -> Self {
-		Self(Arc::clone(&self.0))
+		Self(Arc::clone(&self.0), PhantomData)
 	}
 }
 
-impl<T> From<&Arc<RwLock<T>>> for RoLock<T> {
+impl<T, L: ReadLock<T>> From<&Arc<L>> for RoLock<T, L> {
 	#[inline(always)]
-	fn from(value: &Arc<RwLock<T>>) -> Self {
-		Self(Arc::clone(value))
+	fn from(value: &Arc<L>) -> Self {
+		Self(Arc::clone(value), PhantomData)
 	}
 }